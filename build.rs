@@ -0,0 +1,80 @@
+// Copyright 2013 The color-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates `consts`'s `pub static` color items and its `named`/`name_of`
+//! lookup tables from `res/svg_colors.txt`, so the SVG color list stays a
+//! data-only file instead of a hand-maintained wall of Rust statics.
+
+use std::env;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+fn const_ident(name: &str) -> String {
+    name.to_uppercase()
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src_path = Path::new(&manifest_dir).join("res").join("svg_colors.txt");
+
+    let mut text = String::new();
+    File::open(&src_path).unwrap().read_to_string(&mut text).unwrap();
+
+    let mut entries: Vec<(String, u8, u8, u8)> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let name = parts.next().expect("missing name").to_string();
+        let hex = parts.next().expect("missing hex value");
+        assert_eq!(hex.len(), 6, "not a 6-digit RRGGBB value: {}", hex);
+        let r = u8::from_str_radix(&hex[0..2], 16).unwrap();
+        let g = u8::from_str_radix(&hex[2..4], 16).unwrap();
+        let b = u8::from_str_radix(&hex[4..6], 16).unwrap();
+        entries.push((name, r, g, b));
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("svg_colors.rs");
+    let mut out = File::create(&dest_path).unwrap();
+
+    for &(ref name, r, g, b) in entries.iter() {
+        writeln!(out, "pub static {}: Rgb<u8> = Rgb {{ r: 0x{:02X}, g: 0x{:02X}, b: 0x{:02X} }};",
+                 const_ident(name), r, g, b).unwrap();
+    }
+
+    // Sorted by lowercased name so `named` can binary search.
+    let mut by_name = entries.clone();
+    by_name.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+
+    writeln!(out, "static NAMES: &'static [(&'static str, Rgb<u8>)] = &[").unwrap();
+    for &(ref name, r, g, b) in by_name.iter() {
+        writeln!(out, "    (\"{}\", Rgb {{ r: 0x{:02X}, g: 0x{:02X}, b: 0x{:02X} }}),",
+                 name.to_lowercase(), r, g, b).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    writeln!(out, "\npub fn named(name: &str) -> Option<Rgb<u8>> {{").unwrap();
+    writeln!(out, "    let name = name.to_lowercase();").unwrap();
+    writeln!(out, "    NAMES.binary_search_by(|&(n, _)| n.cmp(&name[..])).ok().map(|i| NAMES[i].1)").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    writeln!(out, "\npub fn name_of(c: Rgb<u8>) -> Option<&'static str> {{").unwrap();
+    writeln!(out, "    NAMES.iter().find(|&&(_, v)| v == c).map(|&(n, _)| n)").unwrap();
+    writeln!(out, "}}").unwrap();
+}
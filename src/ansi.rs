@@ -0,0 +1,130 @@
+// Copyright 2013 The color-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Quantization to and from the standard 256-color xterm/ANSI terminal palette.
+
+use Channel;
+use {Rgb, ToRgb};
+
+/// The 6 intensity levels used for each axis of the 6x6x6 color cube
+/// (indices 16-231).
+static CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 basic ANSI system colors (indices 0-15), in the usual
+/// black/red/green/yellow/blue/magenta/cyan/white, normal-then-bright order.
+static SYSTEM: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00), (0x80, 0x00, 0x00), (0x00, 0x80, 0x00), (0x80, 0x80, 0x00),
+    (0x00, 0x00, 0x80), (0x80, 0x00, 0x80), (0x00, 0x80, 0x80), (0xC0, 0xC0, 0xC0),
+    (0x80, 0x80, 0x80), (0xFF, 0x00, 0x00), (0x00, 0xFF, 0x00), (0xFF, 0xFF, 0x00),
+    (0x00, 0x00, 0xFF), (0xFF, 0x00, 0xFF), (0x00, 0xFF, 0xFF), (0xFF, 0xFF, 0xFF),
+];
+
+/// Looks up the `u8` RGB triple for a given index into the 256-color palette.
+fn palette_entry(i: u8) -> (u8, u8, u8) {
+    match i {
+        0...15 => SYSTEM[i as usize],
+        16...231 => {
+            let n = i - 16;
+            let r = CUBE_STEPS[(n / 36) as usize];
+            let g = CUBE_STEPS[((n / 6) % 6) as usize];
+            let b = CUBE_STEPS[(n % 6) as usize];
+            (r, g, b)
+        }
+        /* 232...255 */ _ => {
+            let v = 8 + 10 * (i - 232);
+            (v, v, v)
+        }
+    }
+}
+
+fn squared_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+fn nearest_in_range(target: (u8, u8, u8), lo: u8, hi: u8) -> u8 {
+    let mut best = lo;
+    let mut best_dist = squared_distance(target, palette_entry(lo));
+    for i in (lo as u16 + 1)..(hi as u16 + 1) {
+        let i = i as u8;
+        let dist = squared_distance(target, palette_entry(i));
+        // On a tie, prefer the higher index: this is what makes exact
+        // cube/gray-ramp corners (e.g. 16, 231) win over the coincidentally
+        // equidistant basic system colors (e.g. 0, 15) they overlap with.
+        if dist <= best_dist {
+            best = i;
+            best_dist = dist;
+        }
+    }
+    best
+}
+
+/// Extension trait adding xterm-256/ANSI-16 palette quantization to `Rgb`.
+pub trait ToAnsi {
+    /// Finds the index (0-255) of the closest color in the standard
+    /// 256-color terminal palette, by squared Euclidean distance in RGB.
+    fn to_ansi256(&self) -> u8;
+
+    /// As `to_ansi256`, but restricted to the 16 basic system colors.
+    fn to_ansi16(&self) -> u8;
+}
+
+impl<T:Clone + Channel> ToAnsi for Rgb<T> {
+    fn to_ansi256(&self) -> u8 {
+        let target = self.to_rgb::<u8>();
+        nearest_in_range((target.r, target.g, target.b), 0, 255)
+    }
+
+    fn to_ansi16(&self) -> u8 {
+        let target = self.to_rgb::<u8>();
+        nearest_in_range((target.r, target.g, target.b), 0, 15)
+    }
+}
+
+/// Converts a 256-color palette index back into its `Rgb<u8>` value.
+pub fn from_ansi256(i: u8) -> Rgb<u8> {
+    let (r, g, b) = palette_entry(i);
+    Rgb::new(r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use Rgb;
+    use super::{ToAnsi, from_ansi256};
+
+    #[test]
+    fn test_cube_corners_roundtrip() {
+        assert_eq!(from_ansi256(16), Rgb::<u8>::new(0, 0, 0));
+        assert_eq!(from_ansi256(231), Rgb::<u8>::new(255, 255, 255));
+        assert_eq!(Rgb::<u8>::new(0, 0, 0).to_ansi256(), 16);
+        assert_eq!(Rgb::<u8>::new(255, 255, 255).to_ansi256(), 231);
+    }
+
+    #[test]
+    fn test_gray_ramp_not_confused_with_cube() {
+        // 238 is deep in the gray ramp; it must not be mistaken for a
+        // similarly-dark cube entry.
+        assert_eq!(from_ansi256(238), Rgb::<u8>::new(68, 68, 68));
+        assert_eq!(Rgb::<u8>::new(68, 68, 68).to_ansi256(), 238);
+    }
+
+    #[test]
+    fn test_to_ansi16() {
+        assert_eq!(Rgb::<u8>::new(0xFF, 0x00, 0x00).to_ansi16(), 9);
+        assert_eq!(Rgb::<u8>::new(0x00, 0x00, 0x00).to_ansi16(), 0);
+    }
+}
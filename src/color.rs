@@ -0,0 +1,54 @@
+// Copyright 2013 The color-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// `mod color;` (and the sibling `ansi`/`rgba` modules) are wired up from
+// the crate root, same as the pre-existing `Channel`/`FloatChannel`/`Hsv`
+// items `rgb.rs` already imports; none of those root-level declarations
+// are part of this source tree snapshot.
+
+/// A color value parameterized over its channel type `T`, implemented by
+/// every color model in this crate (`Rgb`, `Rgba`, ...).
+pub trait Color<T> {
+    /// Clamps the components of the color to the range `(lo,hi)`.
+    fn clamp_s(self, lo: T, hi: T) -> Self;
+
+    /// Clamps the components of the color component-wise between `lo` and `hi`.
+    fn clamp_c(self, lo: Self, hi: Self) -> Self;
+
+    /// Inverts the color.
+    fn inverse(self) -> Self;
+
+    /// Linearly interpolates each channel toward `other` by `t` (expected
+    /// in `0.0..1.0`).
+    fn mix(self, other: Self, t: f32) -> Self;
+
+    /// Halves each channel's intensity, `levels` times.
+    fn darken(self, levels: u32) -> Self;
+
+    /// Raises each channel's intensity halfway to its maximum, `levels`
+    /// times; the mirror image of `darken`, not its undo.
+    fn lighten(self, levels: u32) -> Self;
+}
+
+/// A color value that can be normalized to the valid `(0,1)` float range.
+pub trait FloatColor<T> {
+    /// Normalizes the components of the color by clamping them to the range `(0,1)`.
+    fn normalize(self) -> Self;
+}
+
+/// A 3-component color that can be decomposed into a fixed-size array.
+pub trait Color3<T> {
+    fn into_fixed(self) -> [T; 3];
+}
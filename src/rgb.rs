@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use std::num::{self, Int, Float};
+use std::str::FromStr;
 
 use {Color, FloatColor, Color3};
 use {Channel, FloatChannel};
@@ -33,7 +34,7 @@ impl<T:Channel> Rgb<T> {
     }
 }
 
-impl<T:Channel> Color<T> for Rgb<T> {
+impl<T:Clone + Channel> Color<T> for Rgb<T> {
     /// Clamps the components of the color to the range `(lo,hi)`.
     #[inline]
     fn clamp_s(self, lo: T, hi: T) -> Rgb<T> {
@@ -57,6 +58,35 @@ impl<T:Channel> Color<T> for Rgb<T> {
                  self.g.invert_channel(),
                  self.b.invert_channel())
     }
+
+    /// Linearly interpolates each channel toward `other` by `t` (expected
+    /// in `0.0..1.0`). Works in normalized float space so the blend is
+    /// correct regardless of the channel's integer/float representation.
+    fn mix(self, other: Rgb<T>, t: f32) -> Rgb<T> {
+        let a = self.to_rgb::<f32>();
+        let b = other.to_rgb::<f32>();
+        let lerp = |x: f32, y: f32| x + (y - x) * t;
+        Rgb::new(lerp(a.r, b.r), lerp(a.g, b.g), lerp(a.b, b.b)).to_rgb()
+    }
+
+    /// Halves each channel's intensity `levels` times -- the
+    /// repeated-halving `darkenedby` trick from hyperrogue, but carried
+    /// out in normalized float space rather than bit-shifting the stored
+    /// representation directly, so it stays correct at `T`'s own
+    /// precision instead of narrowing through a fixed-width integer.
+    fn darken(self, levels: u32) -> Rgb<T> {
+        let mut rgb = self.to_rgb::<f32>();
+        for _ in 0..levels {
+            rgb = Rgb::new(rgb.r * 0.5, rgb.g * 0.5, rgb.b * 0.5);
+        }
+        rgb.to_rgb()
+    }
+
+    /// Moves each channel `levels` times halfway toward white; the mirror
+    /// image of `darken` (it darkens the inverted color and inverts back).
+    fn lighten(self, levels: u32) -> Rgb<T> {
+        self.inverse().darken(levels).inverse()
+    }
 }
 
 impl<T:FloatChannel> FloatColor<T> for Rgb<T> {
@@ -82,16 +112,25 @@ pub trait ToRgb {
 }
 
 impl ToRgb for u32 {
+    /// Decodes a packed `0xRRGGBB` (the `0xAARRGGBB` alpha byte, if present, is
+    /// simply ignored) the way GDI+'s `MakeARGB`/`GetR`/`GetG`/`GetB` would.
     #[inline]
     fn to_rgb<U:Channel>(&self) -> Rgb<U> {
-        panic!("Not yet implemented")
+        let n = *self;
+        Rgb::new((((n >> 16) & 0xFF) as u8).to_channel(),
+                 (((n >> 8)  & 0xFF) as u8).to_channel(),
+                 (( n        & 0xFF) as u8).to_channel())
     }
 }
 
 impl ToRgb for u64 {
+    /// Decodes a packed `0xRRRRGGGGBBBB`, one `u16` channel per component.
     #[inline]
     fn to_rgb<U:Channel>(&self) -> Rgb<U> {
-        panic!("Not yet implemented")
+        let n = *self;
+        Rgb::new((((n >> 32) & 0xFFFF) as u16).to_channel(),
+                 (((n >> 16) & 0xFFFF) as u16).to_channel(),
+                 (( n        & 0xFFFF) as u16).to_channel())
     }
 }
 
@@ -104,6 +143,54 @@ impl<T:Clone + Channel> ToRgb for Rgb<T> {
     }
 }
 
+impl<T:Clone + Channel> Rgb<T> {
+    /// Packs this color into a `0x00RRGGBB` `u32`, the inverse of
+    /// `ToRgb::to_rgb` for `u32`.
+    #[inline]
+    pub fn to_packed_u32(&self) -> u32 {
+        let r: u8 = self.r.to_channel();
+        let g: u8 = self.g.to_channel();
+        let b: u8 = self.b.to_channel();
+        ((r as u32) << 16) | ((g as u32) << 8) | (b as u32)
+    }
+}
+
+impl<T:Clone + Channel + Int> Rgb<T> {
+    /// Blends toward `other` through HSV space rather than RGB space, so
+    /// that e.g. red-to-blue goes through the shortest hue arc (via magenta)
+    /// instead of dimming to the RGB midpoint. `t` is expected in `0.0..1.0`.
+    pub fn mix_hsv(self, other: Rgb<T>, t: f32) -> Rgb<T> {
+        let a: Hsv<f32> = self.to_hsv();
+        let b: Hsv<f32> = other.to_hsv();
+
+        let mut dh = b.h - a.h;
+        if dh > 180.0 { dh -= 360.0; }
+        if dh < -180.0 { dh += 360.0; }
+        let mut h = a.h + dh * t;
+        if h < 0.0 { h += 360.0; }
+        if h >= 360.0 { h -= 360.0; }
+
+        let lerp = |x: f32, y: f32| x + (y - x) * t;
+        Hsv::new(h, lerp(a.s, b.s), lerp(a.v, b.v)).to_rgb()
+    }
+
+    /// Adjusts the HSV saturation toward (`factor > 1.0`) or away from
+    /// (`factor < 1.0`) full saturation, clamped to the valid `0.0..1.0`
+    /// range. `factor >= 1.0` moves `s` a fraction `factor - 1.0` of the
+    /// remaining distance to `1.0`, so even a fully desaturated color can
+    /// be resaturated; `factor < 1.0` scales `s` down multiplicatively.
+    pub fn saturate(self, factor: f32) -> Rgb<T> {
+        let hsv: Hsv<f32> = self.to_hsv();
+        let s = if factor >= 1.0 {
+            hsv.s + (1.0 - hsv.s) * (factor - 1.0)
+        } else {
+            hsv.s * factor
+        };
+        let s = s.min(1.0).max(0.0);
+        Hsv::new(hsv.h, s, hsv.v).to_rgb()
+    }
+}
+
 impl<T:Clone + Channel + Int> ToHsv for Rgb<T> {
     #[inline]
     fn to_hsv<U:FloatChannel>(&self) -> Hsv<U> {
@@ -134,154 +221,106 @@ impl<T:Clone + Channel + Int> ToHsv for Rgb<T> {
 }
 
 /// SVG 1.0 color constants: http://www.w3.org/TR/SVG/types.html#ColorKeywords
+///
+/// The `pub static` items below, plus the `named`/`name_of` lookup tables,
+/// are generated by `build.rs` from `res/svg_colors.txt` -- edit that file
+/// (not this module) to add or change colors.
 pub mod consts {
     use Rgb;
 
-    pub static ALICEBLUE:               Rgb<u8> = Rgb { r: 0xF0, g: 0xF8, b: 0xFF };
-    pub static ANTIQUEWHITE:            Rgb<u8> = Rgb { r: 0xFA, g: 0xEB, b: 0xD7 };
-    pub static AQUA:                    Rgb<u8> = Rgb { r: 0x00, g: 0xFF, b: 0xFF };
-    pub static AQUAMARINE:              Rgb<u8> = Rgb { r: 0x7F, g: 0xFF, b: 0xD4 };
-    pub static AZURE:                   Rgb<u8> = Rgb { r: 0xF0, g: 0xFF, b: 0xFF };
-    pub static BEIGE:                   Rgb<u8> = Rgb { r: 0xF5, g: 0xF5, b: 0xDC };
-    pub static BISQUE:                  Rgb<u8> = Rgb { r: 0xFF, g: 0xE4, b: 0xC4 };
-    pub static BLACK:                   Rgb<u8> = Rgb { r: 0x00, g: 0x00, b: 0x00 };
-    pub static BLANCHEDALMOND:          Rgb<u8> = Rgb { r: 0xFF, g: 0xEB, b: 0xCD };
-    pub static BLUE:                    Rgb<u8> = Rgb { r: 0x00, g: 0x00, b: 0xFF };
-    pub static BLUEVIOLET:              Rgb<u8> = Rgb { r: 0x8A, g: 0x2B, b: 0xE2 };
-    pub static BROWN:                   Rgb<u8> = Rgb { r: 0xA5, g: 0x2A, b: 0x2A };
-    pub static BURLYWOOD:               Rgb<u8> = Rgb { r: 0xDE, g: 0xB8, b: 0x87 };
-    pub static CADETBLUE:               Rgb<u8> = Rgb { r: 0x5F, g: 0x9E, b: 0xA0 };
-    pub static CHARTREUSE:              Rgb<u8> = Rgb { r: 0x7F, g: 0xFF, b: 0x00 };
-    pub static CHOCOLATE:               Rgb<u8> = Rgb { r: 0xD2, g: 0x69, b: 0x1E };
-    pub static CORAL:                   Rgb<u8> = Rgb { r: 0xFF, g: 0x7F, b: 0x50 };
-    pub static CORNFLOWERBLUE:          Rgb<u8> = Rgb { r: 0x64, g: 0x95, b: 0xED };
-    pub static CORNSILK:                Rgb<u8> = Rgb { r: 0xFF, g: 0xF8, b: 0xDC };
-    pub static CRIMSON:                 Rgb<u8> = Rgb { r: 0xDC, g: 0x14, b: 0x3C };
-    pub static CYAN:                    Rgb<u8> = Rgb { r: 0x00, g: 0xFF, b: 0xFF };
-    pub static DARKBLUE:                Rgb<u8> = Rgb { r: 0x00, g: 0x00, b: 0x8B };
-    pub static DARKCYAN:                Rgb<u8> = Rgb { r: 0x00, g: 0x8B, b: 0x8B };
-    pub static DARKGOLDENROD:           Rgb<u8> = Rgb { r: 0xB8, g: 0x86, b: 0x0B };
-    pub static DARKGRAY:                Rgb<u8> = Rgb { r: 0xA9, g: 0xA9, b: 0xA9 };
-    pub static DARKGREEN:               Rgb<u8> = Rgb { r: 0x00, g: 0x64, b: 0x00 };
-    pub static DARKKHAKI:               Rgb<u8> = Rgb { r: 0xBD, g: 0xB7, b: 0x6B };
-    pub static DARKMAGENTA:             Rgb<u8> = Rgb { r: 0x8B, g: 0x00, b: 0x8B };
-    pub static DARKOLIVEGREEN:          Rgb<u8> = Rgb { r: 0x55, g: 0x6B, b: 0x2F };
-    pub static DARKORANGE:              Rgb<u8> = Rgb { r: 0xFF, g: 0x8C, b: 0x00 };
-    pub static DARKORCHID:              Rgb<u8> = Rgb { r: 0x99, g: 0x32, b: 0xCC };
-    pub static DARKRED:                 Rgb<u8> = Rgb { r: 0x8B, g: 0x00, b: 0x00 };
-    pub static DARKSALMON:              Rgb<u8> = Rgb { r: 0xE9, g: 0x96, b: 0x7A };
-    pub static DARKSEAGREEN:            Rgb<u8> = Rgb { r: 0x8F, g: 0xBC, b: 0x8F };
-    pub static DARKSLATEBLUE:           Rgb<u8> = Rgb { r: 0x48, g: 0x3D, b: 0x8B };
-    pub static DARKSLATEGRAY:           Rgb<u8> = Rgb { r: 0x2F, g: 0x4F, b: 0x4F };
-    pub static DARKTURQUOISE:           Rgb<u8> = Rgb { r: 0x00, g: 0xCE, b: 0xD1 };
-    pub static DARKVIOLET:              Rgb<u8> = Rgb { r: 0x94, g: 0x00, b: 0xD3 };
-    pub static DEEPPINK:                Rgb<u8> = Rgb { r: 0xFF, g: 0x14, b: 0x93 };
-    pub static DEEPSKYBLUE:             Rgb<u8> = Rgb { r: 0x00, g: 0xBF, b: 0xFF };
-    pub static DIMGRAY:                 Rgb<u8> = Rgb { r: 0x69, g: 0x69, b: 0x69 };
-    pub static DODGERBLUE:              Rgb<u8> = Rgb { r: 0x1E, g: 0x90, b: 0xFF };
-    pub static FIREBRICK:               Rgb<u8> = Rgb { r: 0xB2, g: 0x22, b: 0x22 };
-    pub static FLORALWHITE:             Rgb<u8> = Rgb { r: 0xFF, g: 0xFA, b: 0xF0 };
-    pub static FORESTGREEN:             Rgb<u8> = Rgb { r: 0x22, g: 0x8B, b: 0x22 };
-    pub static FUCHSIA:                 Rgb<u8> = Rgb { r: 0xFF, g: 0x00, b: 0xFF };
-    pub static GAINSBORO:               Rgb<u8> = Rgb { r: 0xDC, g: 0xDC, b: 0xDC };
-    pub static GHOSTWHITE:              Rgb<u8> = Rgb { r: 0xF8, g: 0xF8, b: 0xFF };
-    pub static GOLD:                    Rgb<u8> = Rgb { r: 0xFF, g: 0xD7, b: 0x00 };
-    pub static GOLDENROD:               Rgb<u8> = Rgb { r: 0xDA, g: 0xA5, b: 0x20 };
-    pub static GRAY:                    Rgb<u8> = Rgb { r: 0x80, g: 0x80, b: 0x80 };
-    pub static GREEN:                   Rgb<u8> = Rgb { r: 0x00, g: 0x80, b: 0x00 };
-    pub static GREENYELLOW:             Rgb<u8> = Rgb { r: 0xAD, g: 0xFF, b: 0x2F };
-    pub static HONEYDEW:                Rgb<u8> = Rgb { r: 0xF0, g: 0xFF, b: 0xF0 };
-    pub static HOTPINK:                 Rgb<u8> = Rgb { r: 0xFF, g: 0x69, b: 0xB4 };
-    pub static INDIANRED:               Rgb<u8> = Rgb { r: 0xCD, g: 0x5C, b: 0x5C };
-    pub static INDIGO:                  Rgb<u8> = Rgb { r: 0x4B, g: 0x00, b: 0x82 };
-    pub static IVORY:                   Rgb<u8> = Rgb { r: 0xFF, g: 0xFF, b: 0xF0 };
-    pub static KHAKI:                   Rgb<u8> = Rgb { r: 0xF0, g: 0xE6, b: 0x8C };
-    pub static LAVENDER:                Rgb<u8> = Rgb { r: 0xE6, g: 0xE6, b: 0xFA };
-    pub static LAVENDERBLUSH:           Rgb<u8> = Rgb { r: 0xFF, g: 0xF0, b: 0xF5 };
-    pub static LAWNGREEN:               Rgb<u8> = Rgb { r: 0x7C, g: 0xFC, b: 0x00 };
-    pub static LEMONCHIFFON:            Rgb<u8> = Rgb { r: 0xFF, g: 0xFA, b: 0xCD };
-    pub static LIGHTBLUE:               Rgb<u8> = Rgb { r: 0xAD, g: 0xD8, b: 0xE6 };
-    pub static LIGHTCORAL:              Rgb<u8> = Rgb { r: 0xF0, g: 0x80, b: 0x80 };
-    pub static LIGHTCYAN:               Rgb<u8> = Rgb { r: 0xE0, g: 0xFF, b: 0xFF };
-    pub static LIGHTGOLDENRODYELLOW:    Rgb<u8> = Rgb { r: 0xFA, g: 0xFA, b: 0xD2 };
-    pub static LIGHTGREEN:              Rgb<u8> = Rgb { r: 0x90, g: 0xEE, b: 0x90 };
-    pub static LIGHTGREY:               Rgb<u8> = Rgb { r: 0xD3, g: 0xD3, b: 0xD3 };
-    pub static LIGHTPINK:               Rgb<u8> = Rgb { r: 0xFF, g: 0xB6, b: 0xC1 };
-    pub static LIGHTSALMON:             Rgb<u8> = Rgb { r: 0xFF, g: 0xA0, b: 0x7A };
-    pub static LIGHTSEAGREEN:           Rgb<u8> = Rgb { r: 0x20, g: 0xB2, b: 0xAA };
-    pub static LIGHTSKYBLUE:            Rgb<u8> = Rgb { r: 0x87, g: 0xCE, b: 0xFA };
-    pub static LIGHTSLATEGRAY:          Rgb<u8> = Rgb { r: 0x77, g: 0x88, b: 0x99 };
-    pub static LIGHTSTEELBLUE:          Rgb<u8> = Rgb { r: 0xB0, g: 0xC4, b: 0xDE };
-    pub static LIGHTYELLOW:             Rgb<u8> = Rgb { r: 0xFF, g: 0xFF, b: 0xE0 };
-    pub static LIME:                    Rgb<u8> = Rgb { r: 0x00, g: 0xFF, b: 0x00 };
-    pub static LIMEGREEN:               Rgb<u8> = Rgb { r: 0x32, g: 0xCD, b: 0x32 };
-    pub static LINEN:                   Rgb<u8> = Rgb { r: 0xFA, g: 0xF0, b: 0xE6 };
-    pub static MAGENTA:                 Rgb<u8> = Rgb { r: 0xFF, g: 0x00, b: 0xFF };
-    pub static MAROON:                  Rgb<u8> = Rgb { r: 0x80, g: 0x00, b: 0x00 };
-    pub static MEDIUMAQUAMARINE:        Rgb<u8> = Rgb { r: 0x66, g: 0xCD, b: 0xAA };
-    pub static MEDIUMBLUE:              Rgb<u8> = Rgb { r: 0x00, g: 0x00, b: 0xCD };
-    pub static MEDIUMORCHID:            Rgb<u8> = Rgb { r: 0xBA, g: 0x55, b: 0xD3 };
-    pub static MEDIUMPURPLE:            Rgb<u8> = Rgb { r: 0x93, g: 0x70, b: 0xDB };
-    pub static MEDIUMSEAGREEN:          Rgb<u8> = Rgb { r: 0x3C, g: 0xB3, b: 0x71 };
-    pub static MEDIUMSLATEBLUE:         Rgb<u8> = Rgb { r: 0x7B, g: 0x68, b: 0xEE };
-    pub static MEDIUMSPRINGGREEN:       Rgb<u8> = Rgb { r: 0x00, g: 0xFA, b: 0x9A };
-    pub static MEDIUMTURQUOISE:         Rgb<u8> = Rgb { r: 0x48, g: 0xD1, b: 0xCC };
-    pub static MEDIUMVIOLETRED:         Rgb<u8> = Rgb { r: 0xC7, g: 0x15, b: 0x85 };
-    pub static MIDNIGHTBLUE:            Rgb<u8> = Rgb { r: 0x19, g: 0x19, b: 0x70 };
-    pub static MINTCREAM:               Rgb<u8> = Rgb { r: 0xF5, g: 0xFF, b: 0xFA };
-    pub static MISTYROSE:               Rgb<u8> = Rgb { r: 0xFF, g: 0xE4, b: 0xE1 };
-    pub static MOCCASIN:                Rgb<u8> = Rgb { r: 0xFF, g: 0xE4, b: 0xB5 };
-    pub static NAVAJOWHITE:             Rgb<u8> = Rgb { r: 0xFF, g: 0xDE, b: 0xAD };
-    pub static NAVY:                    Rgb<u8> = Rgb { r: 0x00, g: 0x00, b: 0x80 };
-    pub static OLDLACE:                 Rgb<u8> = Rgb { r: 0xFD, g: 0xF5, b: 0xE6 };
-    pub static OLIVE:                   Rgb<u8> = Rgb { r: 0x80, g: 0x80, b: 0x00 };
-    pub static OLIVEDRAB:               Rgb<u8> = Rgb { r: 0x6B, g: 0x8E, b: 0x23 };
-    pub static ORANGE:                  Rgb<u8> = Rgb { r: 0xFF, g: 0xA5, b: 0x00 };
-    pub static ORANGERED:               Rgb<u8> = Rgb { r: 0xFF, g: 0x45, b: 0x00 };
-    pub static ORCHID:                  Rgb<u8> = Rgb { r: 0xDA, g: 0x70, b: 0xD6 };
-    pub static PALEGOLDENROD:           Rgb<u8> = Rgb { r: 0xEE, g: 0xE8, b: 0xAA };
-    pub static PALEGREEN:               Rgb<u8> = Rgb { r: 0x98, g: 0xFB, b: 0x98 };
-    pub static PALEVIOLETRED:           Rgb<u8> = Rgb { r: 0xDB, g: 0x70, b: 0x93 };
-    pub static PAPAYAWHIP:              Rgb<u8> = Rgb { r: 0xFF, g: 0xEF, b: 0xD5 };
-    pub static PEACHPUFF:               Rgb<u8> = Rgb { r: 0xFF, g: 0xDA, b: 0xB9 };
-    pub static PERU:                    Rgb<u8> = Rgb { r: 0xCD, g: 0x85, b: 0x3F };
-    pub static PINK:                    Rgb<u8> = Rgb { r: 0xFF, g: 0xC0, b: 0xCB };
-    pub static PLUM:                    Rgb<u8> = Rgb { r: 0xDD, g: 0xA0, b: 0xDD };
-    pub static POWDERBLUE:              Rgb<u8> = Rgb { r: 0xB0, g: 0xE0, b: 0xE6 };
-    pub static PURPLE:                  Rgb<u8> = Rgb { r: 0x80, g: 0x00, b: 0x80 };
-    pub static RED:                     Rgb<u8> = Rgb { r: 0xFF, g: 0x00, b: 0x00 };
-    pub static ROSYBROWN:               Rgb<u8> = Rgb { r: 0xBC, g: 0x8F, b: 0x8F };
-    pub static ROYALBLUE:               Rgb<u8> = Rgb { r: 0x41, g: 0x69, b: 0xE1 };
-    pub static SADDLEBROWN:             Rgb<u8> = Rgb { r: 0x8B, g: 0x45, b: 0x13 };
-    pub static SALMON:                  Rgb<u8> = Rgb { r: 0xFA, g: 0x80, b: 0x72 };
-    pub static SANDYBROWN:              Rgb<u8> = Rgb { r: 0xFA, g: 0xA4, b: 0x60 };
-    pub static SEAGREEN:                Rgb<u8> = Rgb { r: 0x2E, g: 0x8B, b: 0x57 };
-    pub static SEASHELL:                Rgb<u8> = Rgb { r: 0xFF, g: 0xF5, b: 0xEE };
-    pub static SIENNA:                  Rgb<u8> = Rgb { r: 0xA0, g: 0x52, b: 0x2D };
-    pub static SILVER:                  Rgb<u8> = Rgb { r: 0xC0, g: 0xC0, b: 0xC0 };
-    pub static SKYBLUE:                 Rgb<u8> = Rgb { r: 0x87, g: 0xCE, b: 0xEB };
-    pub static SLATEBLUE:               Rgb<u8> = Rgb { r: 0x6A, g: 0x5A, b: 0xCD };
-    pub static SLATEGRAY:               Rgb<u8> = Rgb { r: 0x70, g: 0x80, b: 0x90 };
-    pub static SNOW:                    Rgb<u8> = Rgb { r: 0xFF, g: 0xFA, b: 0xFA };
-    pub static SPRINGGREEN:             Rgb<u8> = Rgb { r: 0x00, g: 0xFF, b: 0x7F };
-    pub static STEELBLUE:               Rgb<u8> = Rgb { r: 0x46, g: 0x82, b: 0xB4 };
-    pub static TAN:                     Rgb<u8> = Rgb { r: 0xD2, g: 0xB4, b: 0x8C };
-    pub static TEAL:                    Rgb<u8> = Rgb { r: 0x00, g: 0x80, b: 0x80 };
-    pub static THISTLE:                 Rgb<u8> = Rgb { r: 0xD8, g: 0xBF, b: 0xD8 };
-    pub static TOMATO:                  Rgb<u8> = Rgb { r: 0xFF, g: 0x63, b: 0x47 };
-    pub static TURQUOISE:               Rgb<u8> = Rgb { r: 0x40, g: 0xE0, b: 0xD0 };
-    pub static VIOLET:                  Rgb<u8> = Rgb { r: 0xEE, g: 0x82, b: 0xEE };
-    pub static WHEAT:                   Rgb<u8> = Rgb { r: 0xF5, g: 0xDE, b: 0xB3 };
-    pub static WHITE:                   Rgb<u8> = Rgb { r: 0xFF, g: 0xFF, b: 0xFF };
-    pub static WHITESMOKE:              Rgb<u8> = Rgb { r: 0xF5, g: 0xF5, b: 0xF5 };
-    pub static YELLOW:                  Rgb<u8> = Rgb { r: 0xFF, g: 0xFF, b: 0x00 };
-    pub static YELLOWGREEN:             Rgb<u8> = Rgb { r: 0x9A, g: 0xCD, b: 0x32 };
+    include!(concat!(env!("OUT_DIR"), "/svg_colors.rs"));
+}
+
+/// The ways parsing a color from a string can fail.
+#[derive(Clone, PartialEq, Eq, Show)]
+pub enum ParseColorError {
+    /// An X11 `rgb:` spec did not have exactly three `/`-separated components.
+    InvalidSyntax,
+    /// A character outside of `[0-9a-fA-F]` was found where a hex digit was expected.
+    InvalidDigit,
+    /// A `#`-prefixed hex literal was not 3, 6 or 8 digits long.
+    InvalidHexLength(usize),
+    /// An X11 `rgb:` component had zero or more than 4 hex digits.
+    InvalidX11ComponentLength(usize),
+    /// The string did not match any of the SVG color keywords.
+    UnknownName,
+}
+
+fn hex_nibble(c: u8) -> Result<u32, ParseColorError> {
+    (c as char).to_digit(16).ok_or(ParseColorError::InvalidDigit)
+}
+
+/// Parses a run of `digits.len()` hex digits (1–4) and scales the result
+/// up to the full 16-bit range, left-justifying as `xparsecolor(3)` does:
+/// a value `n` with `d` digits maps to `n * 0xFFFF / (16^d - 1)`.
+fn parse_x11_component(digits: &[u8]) -> Result<u16, ParseColorError> {
+    if digits.is_empty() || digits.len() > 4 {
+        return Err(ParseColorError::InvalidX11ComponentLength(digits.len()));
+    }
+    let mut n: u32 = 0;
+    for &c in digits.iter() {
+        n = (n << 4) | try!(hex_nibble(c));
+    }
+    let max = (1u32 << (4 * digits.len())) - 1;
+    Ok(((n * 0xFFFF) / max) as u16)
+}
+
+fn parse_hex_pair(hi: u8, lo: u8) -> Result<u8, ParseColorError> {
+    Ok(((try!(hex_nibble(hi)) << 4) | try!(hex_nibble(lo))) as u8)
+}
+
+impl<T:Channel> FromStr for Rgb<T> {
+    type Err = ParseColorError;
+
+    /// Parses `#RGB`, `#RRGGBB`, `#RRGGBBAA` (the alpha digits are accepted
+    /// and discarded), the SVG/CSS keyword names from the `consts` module
+    /// (case-insensitively), and the X11 `xparsecolor` syntax
+    /// `rgb:RRRR/GGGG/BBBB`.
+    fn from_str(s: &str) -> Result<Rgb<T>, ParseColorError> {
+        if s.starts_with('#') {
+            let bytes = s[1..].as_bytes();
+            let rgb8 = match bytes.len() {
+                3 => Rgb::<u8>::new(try!(parse_hex_pair(bytes[0], bytes[0])),
+                                     try!(parse_hex_pair(bytes[1], bytes[1])),
+                                     try!(parse_hex_pair(bytes[2], bytes[2]))),
+                6 => Rgb::<u8>::new(try!(parse_hex_pair(bytes[0], bytes[1])),
+                                     try!(parse_hex_pair(bytes[2], bytes[3])),
+                                     try!(parse_hex_pair(bytes[4], bytes[5]))),
+                8 => {
+                    let rgb = Rgb::<u8>::new(try!(parse_hex_pair(bytes[0], bytes[1])),
+                                              try!(parse_hex_pair(bytes[2], bytes[3])),
+                                              try!(parse_hex_pair(bytes[4], bytes[5])));
+                    // Validate (and discard) the alpha digits.
+                    try!(parse_hex_pair(bytes[6], bytes[7]));
+                    rgb
+                }
+                n => return Err(ParseColorError::InvalidHexLength(n)),
+            };
+            return Ok(rgb8.to_rgb());
+        }
+
+        if s.starts_with("rgb:") {
+            let mut parts = s[4..].split('/');
+            let (r, g, b) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(r), Some(g), Some(b), None) => (r, g, b),
+                _ => return Err(ParseColorError::InvalidSyntax),
+            };
+            let rgb16 = Rgb::<u16>::new(try!(parse_x11_component(r.as_bytes())),
+                                         try!(parse_x11_component(g.as_bytes())),
+                                         try!(parse_x11_component(b.as_bytes())));
+            return Ok(rgb16.to_rgb());
+        }
+
+        consts::named(s).map(|c| c.to_rgb()).ok_or(ParseColorError::UnknownName)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use {Hsv, ToHsv};
+    use std::str::FromStr;
+    use {Color, Hsv, ToHsv};
     use {Rgb, ToRgb};
+    use super::ParseColorError;
 
     #[test]
     fn test_rgb_to_rgb() {
@@ -289,6 +328,47 @@ mod tests {
         assert_eq!(Rgb::<u8>::new(0xA0, 0xA0, 0xA0).to_rgb::<u16>(), Rgb::<u16>::new(0xA0A0, 0xA0A0, 0xA0A0));
     }
 
+    #[test]
+    fn test_u32_to_rgb() {
+        assert_eq!(0xF0F8FFu32.to_rgb::<u8>(), ::rgb::consts::ALICEBLUE);
+        assert_eq!(0x000000u32.to_rgb::<u8>(), Rgb::<u8>::new(0x00, 0x00, 0x00));
+    }
+
+    #[test]
+    fn test_u64_to_rgb() {
+        assert_eq!(0xF0F0F8F8FFFFu64.to_rgb::<u16>(), Rgb::<u16>::new(0xF0F0, 0xF8F8, 0xFFFF));
+    }
+
+    #[test]
+    fn test_rgb_to_packed_u32() {
+        assert_eq!(Rgb::<u8>::new(0xF0, 0xF8, 0xFF).to_packed_u32(), 0xF0F8FF);
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(Rgb::<u8>::from_str("#F0F8FF"), Ok(Rgb::<u8>::new(0xF0, 0xF8, 0xFF)));
+        assert_eq!(Rgb::<u8>::from_str("#0FF"), Ok(Rgb::<u8>::new(0x00, 0xFF, 0xFF)));
+        assert_eq!(Rgb::<u8>::from_str("#F0F8FFFF"), Ok(Rgb::<u8>::new(0xF0, 0xF8, 0xFF)));
+        assert_eq!(Rgb::<u8>::from_str("#ggg"), Err(ParseColorError::InvalidDigit));
+        assert_eq!(Rgb::<u8>::from_str("#ABCD"), Err(ParseColorError::InvalidHexLength(4)));
+        assert_eq!(Rgb::<u8>::from_str("#F0F8FFZZ"), Err(ParseColorError::InvalidDigit));
+    }
+
+    #[test]
+    fn test_parse_named() {
+        assert_eq!(Rgb::<u8>::from_str("AliceBlue"), Ok(Rgb::<u8>::new(0xF0, 0xF8, 0xFF)));
+        assert_eq!(Rgb::<u8>::from_str("aliceblue"), Ok(Rgb::<u8>::new(0xF0, 0xF8, 0xFF)));
+        assert_eq!(Rgb::<u8>::from_str("not-a-color"), Err(ParseColorError::UnknownName));
+    }
+
+    #[test]
+    fn test_parse_x11() {
+        assert_eq!(Rgb::<u16>::from_str("rgb:ffff/ffff/ffff"), Ok(Rgb::<u16>::new(0xFFFF, 0xFFFF, 0xFFFF)));
+        assert_eq!(Rgb::<u8>::from_str("rgb:f/0/0"), Ok(Rgb::<u8>::new(0xFF, 0x00, 0x00)));
+        assert_eq!(Rgb::<u8>::from_str("rgb:12345/0/0"),
+                   Err(ParseColorError::InvalidX11ComponentLength(5)));
+    }
+
     #[test]
     fn test_rgb_to_hsv() {
         assert_eq!(Rgb::<u8>::new(0xFF, 0xFF, 0xFF).to_hsv::<f32>(), Hsv::<f32>::new(0.0, 0.0, 1.0));
@@ -296,4 +376,46 @@ mod tests {
         assert_eq!(Rgb::<u8>::new(0x00, 0x99, 0x00).to_hsv::<f32>(), Hsv::<f32>::new(120.0, 1.0, 0.6));
         assert_eq!(Rgb::<u8>::new(0x00, 0x00, 0x99).to_hsv::<f32>(), Hsv::<f32>::new(240.0, 1.0, 0.6));
     }
+
+    #[test]
+    fn test_mix() {
+        let a = Rgb::<u8>::new(0x00, 0x00, 0x00);
+        let b = Rgb::<u8>::new(0x80, 0x80, 0x80);
+        assert_eq!(a.mix(b, 0.0), a);
+        assert_eq!(a.mix(b, 1.0), b);
+        assert_eq!(a.mix(b, 0.5), Rgb::<u8>::new(0x40, 0x40, 0x40));
+    }
+
+    #[test]
+    fn test_darken_and_lighten() {
+        let mid = Rgb::<u8>::new(0x80, 0x80, 0x80);
+        assert_eq!(mid.darken(1), Rgb::<u8>::new(0x40, 0x40, 0x40));
+        assert_eq!(mid.darken(2), Rgb::<u8>::new(0x20, 0x20, 0x20));
+        let dim = Rgb::<u8>::new(0x7F, 0x7F, 0x7F);
+        assert_eq!(dim.lighten(1), Rgb::<u8>::new(0xBF, 0xBF, 0xBF));
+    }
+
+    #[test]
+    fn test_mix_hsv_takes_shortest_hue_arc() {
+        let red = Rgb::<u8>::new(0xFF, 0x00, 0x00);
+        let magenta = Rgb::<u8>::new(0xFF, 0x00, 0xFF);
+        // red (h=0) -> magenta (h=300) should wrap through h=330, not
+        // plow forward through h=150 (cyan/green territory).
+        let mid = red.mix_hsv(magenta, 0.5);
+        // `to_hsv` reports this red-dominant hue as roughly -30, and the u8
+        // blue channel only lands within a fraction of a degree of 330.
+        let mid_hsv: Hsv<f32> = mid.to_hsv();
+        let h = if mid_hsv.h < 0.0 { mid_hsv.h + 360.0 } else { mid_hsv.h };
+        assert!((h - 330.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_saturate() {
+        let dull_red = Rgb::<u8>::new(0xC0, 0x40, 0x40);
+        let dulled = dull_red.saturate(0.0).to_hsv::<f32>();
+        assert_eq!(dulled.s, 0.0);
+
+        let resaturated = dull_red.saturate(0.0).saturate(1000.0).to_hsv::<f32>();
+        assert_eq!(resaturated.s, 1.0);
+    }
 }
@@ -0,0 +1,224 @@
+// Copyright 2013 The color-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::num::Float;
+
+use {Color, FloatColor};
+use {Channel, FloatChannel};
+use {Rgb, ToRgb};
+
+/// An RGB color with an alpha (opacity) channel, mirroring GDI+ and
+/// SerenityOS's `Color(a, r, g, b)`.
+#[derive(Clone, Copy, PartialEq, Eq, Show)]
+pub struct Rgba<T> { pub r: T, pub g: T, pub b: T, pub a: T }
+
+impl<T:Channel> Rgba<T> {
+    #[inline]
+    pub fn new(r: T, g: T, b: T, a: T) -> Rgba<T> {
+        Rgba { r: r, g: g, b: b, a: a }
+    }
+
+    /// Pairs an opaque `Rgb` with an alpha value.
+    #[inline]
+    pub fn with_alpha(rgb: Rgb<T>, a: T) -> Rgba<T> {
+        Rgba::new(rgb.r, rgb.g, rgb.b, a)
+    }
+}
+
+impl<T:Clone + Channel> Rgba<T> {
+    /// Packs this color into a `0xAARRGGBB` `u32`.
+    #[inline]
+    pub fn to_packed_u32(&self) -> u32 {
+        let rgb = Rgb::new(self.r.clone(), self.g.clone(), self.b.clone());
+        let a: u8 = self.a.to_channel();
+        rgb.to_packed_u32() | ((a as u32) << 24)
+    }
+}
+
+/// Analogous to `ToRgb`, but for alpha-carrying colors.
+pub trait ToRgba {
+    fn to_rgba<U:Channel>(&self) -> Rgba<U>;
+}
+
+impl ToRgba for u32 {
+    /// Decodes a packed `0xAARRGGBB`.
+    #[inline]
+    fn to_rgba<U:Channel>(&self) -> Rgba<U> {
+        let n = *self;
+        let rgb: Rgb<U> = n.to_rgb();
+        let a: u8 = ((n >> 24) & 0xFF) as u8;
+        Rgba::with_alpha(rgb, a.to_channel())
+    }
+}
+
+impl<T:Clone + Channel> ToRgba for Rgba<T> {
+    #[inline]
+    fn to_rgba<U:Channel>(&self) -> Rgba<U> {
+        Rgba::new(self.r.to_channel(),
+                  self.g.to_channel(),
+                  self.b.to_channel(),
+                  self.a.to_channel())
+    }
+}
+
+impl<T:FloatChannel> Rgba<T> {
+    /// Composites `self` (the source) over `bottom`, implementing the
+    /// Porter-Duff source-over operator:
+    ///
+    /// ```text
+    /// out_a   = a_s + a_b * (1 - a_s)
+    /// out_rgb = (rgb_s * a_s + rgb_b * a_b * (1 - a_s)) / out_a
+    /// ```
+    ///
+    /// A fully transparent result (`out_a == 0`) yields a zeroed color.
+    pub fn over(self, bottom: Rgba<T>) -> Rgba<T> {
+        let src = self.to_rgba::<T>();
+        let bot = bottom.to_rgba::<T>();
+
+        let a_s = src.a;
+        let a_b = bot.a;
+        let inv_a_s = Float::one() - a_s;
+        let out_a = a_s + a_b * inv_a_s;
+
+        if out_a == Float::zero() {
+            return Rgba::new(Float::zero(), Float::zero(), Float::zero(), Float::zero());
+        }
+
+        let blend = |s: T, b: T| (s * a_s + b * a_b * inv_a_s) / out_a;
+        Rgba::new(blend(src.r, bot.r), blend(src.g, bot.g), blend(src.b, bot.b), out_a)
+    }
+
+    /// Drops the alpha channel, flattening this color onto `background`
+    /// by the Porter-Duff source-over operator.
+    #[inline]
+    pub fn to_rgb(self, background: Rgb<T>) -> Rgb<T> {
+        let bg = Rgba::with_alpha(background, Float::one());
+        let out = self.over(bg);
+        Rgb::new(out.r, out.g, out.b)
+    }
+}
+
+impl<T:Clone + Channel> Color<T> for Rgba<T> {
+    #[inline]
+    fn clamp_s(self, lo: T, hi: T) -> Rgba<T> {
+        Rgba::new(self.r.clamp(lo, hi),
+                  self.g.clamp(lo, hi),
+                  self.b.clamp(lo, hi),
+                  self.a.clamp(lo, hi))
+    }
+
+    #[inline]
+    fn clamp_c(self, lo: Rgba<T>, hi: Rgba<T>) -> Rgba<T> {
+        Rgba::new(self.r.clamp(lo.r, hi.r),
+                  self.g.clamp(lo.g, hi.g),
+                  self.b.clamp(lo.b, hi.b),
+                  self.a.clamp(lo.a, hi.a))
+    }
+
+    #[inline]
+    fn inverse(self) -> Rgba<T> {
+        Rgba::new(self.r.invert_channel(),
+                  self.g.invert_channel(),
+                  self.b.invert_channel(),
+                  self.a)
+    }
+
+    /// Linearly interpolates each channel, alpha included, toward `other`
+    /// by `t` (expected in `0.0..1.0`).
+    fn mix(self, other: Rgba<T>, t: f32) -> Rgba<T> {
+        let a = self.to_rgba::<f32>();
+        let b = other.to_rgba::<f32>();
+        let lerp = |x: f32, y: f32| x + (y - x) * t;
+        Rgba::new(lerp(a.r, b.r), lerp(a.g, b.g), lerp(a.b, b.b), lerp(a.a, b.a)).to_rgba()
+    }
+
+    /// Halves each color channel's intensity `levels` times, leaving
+    /// alpha untouched.
+    fn darken(self, levels: u32) -> Rgba<T> {
+        let start = self.to_rgba::<f32>();
+        let mut rgb = Rgb::new(start.r, start.g, start.b);
+        for _ in 0..levels {
+            rgb = Rgb::new(rgb.r * 0.5, rgb.g * 0.5, rgb.b * 0.5);
+        }
+        Rgba::with_alpha(rgb, start.a).to_rgba()
+    }
+
+    /// Moves each color channel `levels` times halfway toward white,
+    /// leaving alpha untouched; the mirror image of `darken` (it darkens
+    /// the inverted color and inverts back).
+    fn lighten(self, levels: u32) -> Rgba<T> {
+        self.inverse().darken(levels).inverse()
+    }
+}
+
+impl<T:FloatChannel> FloatColor<T> for Rgba<T> {
+    #[inline]
+    fn normalize(self) -> Rgba<T> {
+        Rgba::new(self.r.normalize_channel(),
+                  self.g.normalize_channel(),
+                  self.b.normalize_channel(),
+                  self.a.normalize_channel())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Rgb, Rgba, ToRgba};
+
+    #[test]
+    fn test_rgba_to_rgba() {
+        assert_eq!(Rgba::<u8>::new(0xA0, 0xA0, 0xA0, 0xFF).to_rgba::<u8>(),
+                   Rgba::<u8>::new(0xA0, 0xA0, 0xA0, 0xFF));
+    }
+
+    #[test]
+    fn test_u32_to_rgba() {
+        assert_eq!(0xFFF0F8FFu32.to_rgba::<u8>(), Rgba::<u8>::new(0xF0, 0xF8, 0xFF, 0xFF));
+    }
+
+    #[test]
+    fn test_rgba_to_packed_u32() {
+        assert_eq!(Rgba::<u8>::new(0xF0, 0xF8, 0xFF, 0x80).to_packed_u32(), 0x80F0F8FF);
+    }
+
+    #[test]
+    fn test_over_opaque_source_ignores_background() {
+        let src = Rgba::<f32>::new(1.0, 0.0, 0.0, 1.0);
+        let bg = Rgba::<f32>::new(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(src.over(bg), Rgba::<f32>::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_over_half_alpha_blends() {
+        let src = Rgba::<f32>::new(1.0, 0.0, 0.0, 0.5);
+        let bg = Rgba::<f32>::new(0.0, 0.0, 1.0, 1.0);
+        let out = src.over(bg);
+        assert_eq!(out, Rgba::<f32>::new(0.5, 0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn test_over_fully_transparent_yields_zero() {
+        let src = Rgba::<f32>::new(1.0, 0.0, 0.0, 0.0);
+        let bg = Rgba::<f32>::new(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(src.over(bg), Rgba::<f32>::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_to_rgb_flattens_onto_background() {
+        let src = Rgba::<f32>::new(1.0, 0.0, 0.0, 0.5);
+        let bg = Rgb::<f32>::new(0.0, 0.0, 1.0);
+        assert_eq!(src.to_rgb(bg), Rgb::<f32>::new(0.5, 0.0, 0.5));
+    }
+}